@@ -0,0 +1,37 @@
+use crate::peca::Peca;
+use crate::posicao::Posicao;
+
+pub struct Casa {
+    pub posicao: Posicao,
+    pub peca: OptionalPeca<Box<dyn Peca>>,
+}
+
+impl Casa {
+    pub fn peca_vazia(&self) -> bool {
+        matches!(self.peca, OptionalPeca::None)
+    }
+}
+
+impl Clone for Casa {
+    fn clone(&self) -> Self {
+        Casa {
+            posicao: self.posicao,
+            peca: match &self.peca {
+                OptionalPeca::None => OptionalPeca::None,
+                OptionalPeca::Some(peca) => OptionalPeca::Some(peca.caixa_clone()),
+            },
+        }
+    }
+}
+
+pub enum OptionalPeca<Peca> {
+    None,
+    Some(Peca),
+}
+
+impl<Peca> OptionalPeca<Peca> {
+    // Takes the piece out, leaving `None` behind (mirrors `Option::take`).
+    pub fn tomar(&mut self) -> OptionalPeca<Peca> {
+        std::mem::replace(self, OptionalPeca::None)
+    }
+}
@@ -0,0 +1,14 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jogador {
+    Branco,
+    Preto,
+}
+
+impl Jogador {
+    pub fn oponente(&self) -> Jogador {
+        match self {
+            Jogador::Branco => Jogador::Preto,
+            Jogador::Preto => Jogador::Branco,
+        }
+    }
+}
@@ -1,16 +1,38 @@
-use std::{collections::HashMap, net::SocketAddr, sync::Arc};
+// Native board/move-generation engine, independent of shakmaty. Not yet wired into the HTTP API
+// (which still runs on shakmaty positions); exercised directly by `tabuleiro::tests`. Allowed to
+// sit dead in the production build until that integration lands.
+#[allow(dead_code)]
+mod casa;
+#[allow(dead_code)]
+mod jogador;
+#[allow(dead_code)]
+mod peca;
+#[allow(dead_code)]
+mod posicao;
+#[allow(dead_code)]
+mod tabuleiro;
+
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use axum::{
     Json, Router,
-    extract::{Path, State},
+    extract::{
+        Path, Query, State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
     http::StatusCode,
     response::{IntoResponse, Response},
     routing::{get, post},
 };
 use serde::{Deserialize, Serialize};
-use shakmaty::{CastlingMode, Chess, Color, Move, Position, san::San, uci::UciMove};
+use shakmaty::{CastlingMode, Chess, Color, Move, Outcome, Position, san::San, uci::UciMove};
 use thiserror::Error;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock, broadcast, oneshot};
 use tower_http::{
     compression::CompressionLayer, cors::CorsLayer, decompression::DecompressionLayer,
     trace::TraceLayer,
@@ -20,17 +42,204 @@ use tracing_subscriber::prelude::*;
 use uuid::Uuid;
 
 type Store = Arc<RwLock<HashMap<Uuid, GameEntry>>>;
+// Holds the first player waiting in `/lobby` until a second one shows up to pair with them.
+type Lobby = Arc<Mutex<Option<LobbyWaiter>>>;
 
 #[derive(Clone)]
 struct AppState {
     store: Store,
+    lobby: Lobby,
 }
 
-#[derive(Debug)]
 struct GameEntry {
     pos: Chess,
     history_uci: Vec<String>,
     history_san: Vec<String>,
+    // Position before each ply was played, so `undo` can restore it without shakmaty's `Chess`
+    // supporting unmake.
+    history_pos: Vec<Chess>,
+    seats: Seats,
+    // Broadcasts every applied move to subscribed `/games/:id/ws` sockets.
+    updates: broadcast::Sender<GameUpdate>,
+    clock: Option<Clock>,
+    // Set once a side's clock runs out; overrides `status_of` from then on.
+    forfeit: Option<SideToMove>,
+}
+
+impl GameEntry {
+    fn new(pos: Chess, clock: Option<Clock>) -> Self {
+        GameEntry {
+            pos,
+            history_uci: Vec::new(),
+            history_san: Vec::new(),
+            history_pos: Vec::new(),
+            seats: Seats::new(),
+            updates: broadcast::channel(32).0,
+            clock,
+            forfeit: None,
+        }
+    }
+}
+
+// Per-side remaining time and Fischer increment for a timed game. Only the side to move's clock
+// runs, counted down from `turn_started`.
+struct Clock {
+    white_remaining: Duration,
+    black_remaining: Duration,
+    increment: Duration,
+    turn_started: Instant,
+}
+
+impl Clock {
+    fn new(base: Duration, increment: Duration) -> Self {
+        Clock {
+            white_remaining: base,
+            black_remaining: base,
+            increment,
+            turn_started: Instant::now(),
+        }
+    }
+
+    fn remaining(&self, color: Color) -> Duration {
+        match color {
+            Color::White => self.white_remaining,
+            Color::Black => self.black_remaining,
+        }
+    }
+
+    // Remaining time for `color`, charging the in-flight turn if it's currently `active`'s move.
+    fn remaining_now(&self, color: Color, active: Color) -> Duration {
+        let remaining = self.remaining(color);
+        if color == active {
+            remaining.saturating_sub(self.turn_started.elapsed())
+        } else {
+            remaining
+        }
+    }
+
+    fn is_expired(&self, active: Color) -> bool {
+        self.remaining_now(active, active) == Duration::ZERO
+    }
+
+    // Charges `mover`'s clock for the turn just played, adds the increment, and starts the next
+    // side's clock.
+    fn record_move(&mut self, mover: Color) {
+        let elapsed = self.turn_started.elapsed();
+        let remaining = match mover {
+            Color::White => &mut self.white_remaining,
+            Color::Black => &mut self.black_remaining,
+        };
+        *remaining = remaining.saturating_sub(elapsed) + self.increment;
+        self.turn_started = Instant::now();
+    }
+}
+
+struct Seats {
+    white: Seat,
+    black: Seat,
+}
+
+impl Seats {
+    fn new() -> Self {
+        Seats {
+            white: Seat::new(),
+            black: Seat::new(),
+        }
+    }
+
+    fn seat(&self, color: Color) -> &Seat {
+        match color {
+            Color::White => &self.white,
+            Color::Black => &self.black,
+        }
+    }
+
+    fn seat_mut(&mut self, color: Color) -> &mut Seat {
+        match color {
+            Color::White => &mut self.white,
+            Color::Black => &mut self.black,
+        }
+    }
+
+    fn color_for_token(&self, token: Uuid) -> Option<Color> {
+        if self.white.reconnect_token == token {
+            Some(Color::White)
+        } else if self.black.reconnect_token == token {
+            Some(Color::Black)
+        } else {
+            None
+        }
+    }
+
+    // True once both seats have been claimed at least once (neither is still `Waiting`), i.e.
+    // this is a lobby-paired multiplayer game rather than a single-seat game created directly
+    // through `/games`.
+    fn is_paired(&self) -> bool {
+        !matches!(self.white.status, PlayerStatus::Waiting)
+            && !matches!(self.black.status, PlayerStatus::Waiting)
+    }
+}
+
+struct Seat {
+    status: PlayerStatus,
+    reconnect_token: Uuid,
+    // Bumped every time the seat goes Reconnecting, so a grace-period timer armed by an earlier
+    // disconnect can tell it's stale once a later disconnect has re-armed the wait.
+    reconnect_generation: u64,
+}
+
+impl Seat {
+    fn new() -> Self {
+        Seat {
+            status: PlayerStatus::Waiting,
+            reconnect_token: Uuid::new_v4(),
+            reconnect_generation: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum PlayerStatus {
+    Waiting,
+    Connected,
+    Reconnecting,
+}
+
+// A client waiting in `/lobby` for an opponent; woken up with the game it's been paired into.
+struct LobbyWaiter {
+    notify: oneshot::Sender<LobbyMatch>,
+}
+
+struct LobbyMatch {
+    game_id: Uuid,
+    color: Color,
+    reconnect_token: Uuid,
+}
+
+#[derive(Deserialize)]
+struct WsJoinQuery {
+    token: Uuid,
+}
+
+// Pushed over `/games/:id/ws` whenever the position or status changes, to both participants.
+// `uci`/`san` are set when the update was a move actually applied; `None` for an update that
+// only changes status, such as an undo or a seat forfeiting after the reconnect grace period.
+#[derive(Clone, Serialize)]
+struct GameUpdate {
+    uci: Option<String>,
+    san: Option<String>,
+    fen: String,
+    status: GameStatus,
+}
+
+// Sent once, right after a socket connects, so the client learns its seat and can reconnect later.
+#[derive(Serialize)]
+struct GameSocketSnapshot {
+    game_id: Uuid,
+    color: SideToMove,
+    reconnect_token: Uuid,
+    game: GameResponse,
 }
 
 #[derive(Serialize)]
@@ -41,18 +250,35 @@ struct GameResponse {
     moves_uci: Vec<String>,
     moves_san: Vec<String>,
     status: GameStatus,
+    seats: SeatsSummary,
+    clock: Option<ClockView>,
+}
+
+#[derive(Serialize)]
+struct SeatsSummary {
+    white: PlayerStatus,
+    black: PlayerStatus,
 }
 
+// Remaining time per side, in whole seconds, as of the moment the response was built.
 #[derive(Serialize)]
+struct ClockView {
+    white_remaining_secs: u64,
+    black_remaining_secs: u64,
+    increment_secs: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "snake_case")]
 enum GameStatus {
     Ongoing { to_move: SideToMove, in_check: bool },
     Checkmate { winner: SideToMove },
     Stalemate,
     Draw,
+    TimeForfeit { winner: SideToMove },
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "lowercase")]
 enum SideToMove {
     White,
@@ -64,6 +290,14 @@ struct CreateGameRequest {
     // Optional. Start new game from default start position if not provided.
     // Note: Custom FEN parsing is supported; invalid FEN returns 400.
     fen: Option<String>,
+    // Optional. Starts each side's clock at `base_secs`, adding `increment_secs` after their move.
+    time_control: Option<TimeControlRequest>,
+}
+
+#[derive(Deserialize)]
+struct TimeControlRequest {
+    base_secs: u64,
+    increment_secs: u64,
 }
 
 #[derive(Serialize)]
@@ -72,10 +306,22 @@ struct CreateGameResponse {
     fen: String,
 }
 
+#[derive(Deserialize)]
+struct ImportPgnRequest {
+    // Raw PGN text: an optional Seven Tag Roster header block followed by movetext.
+    pgn: String,
+}
+
 #[derive(Deserialize)]
 struct ApplyMoveRequest {
     // Move in UCI notation, e.g. "e2e4", "g1f3", "e7e8q" (promotion)
     uci: String,
+    // If true, the server immediately plays a reply with the built-in engine after this move.
+    #[serde(default)]
+    auto_reply: bool,
+    // Reconnect token for a multiplayer seat. Required once a game has been paired through the
+    // lobby (both seats claimed); the move is rejected unless it's that seat's turn.
+    token: Option<Uuid>,
 }
 
 #[derive(Serialize)]
@@ -86,8 +332,43 @@ struct ApplyMoveResponse {
     fen: String,
     legal_moves: Vec<String>,
     status: GameStatus,
+    engine_reply: Option<EngineMove>,
+    clock: Option<ClockView>,
+}
+
+#[derive(Serialize)]
+struct EngineMove {
+    uci: String,
+    san: String,
+}
+
+#[derive(Deserialize)]
+struct BestMoveRequest {
+    depth: u32,
+}
+
+#[derive(Serialize)]
+struct BestMoveResponse {
+    uci: String,
+    san: String,
+    score: i32,
 }
 
+#[derive(Deserialize)]
+struct UndoRequest {
+    // How many plies to take back. Defaults to 1; useful as 2 to undo a human move together with
+    // the engine's auto-reply to it.
+    plies: Option<u32>,
+}
+
+// Depth is capped to bound worst-case search latency.
+const MAX_SEARCH_DEPTH: u32 = 6;
+// Depth used for the engine's own reply when `auto_reply` is set on `apply_move`.
+const AUTO_REPLY_DEPTH: u32 = 3;
+const MATE_SCORE: i32 = 1_000_000;
+// How long a seat may sit `Reconnecting` before the game is forfeited to the other side.
+const RECONNECT_GRACE: Duration = Duration::from_secs(120);
+
 #[derive(Error, Debug)]
 enum ApiError {
     #[error("game not found")]
@@ -96,6 +377,8 @@ enum ApiError {
     BadRequest(String),
     #[error("illegal move: {0}")]
     IllegalMove(String),
+    // Reserved for unexpected failures; no call site constructs this yet.
+    #[allow(dead_code)]
     #[error("internal server error")]
     Internal,
 }
@@ -122,6 +405,7 @@ async fn main() {
 
     let state = AppState {
         store: Arc::new(RwLock::new(HashMap::new())),
+        lobby: Arc::new(Mutex::new(None)),
     };
 
     let app = Router::new()
@@ -130,6 +414,12 @@ async fn main() {
         .route("/games", post(create_game))
         .route("/games/:id", get(get_game).delete(delete_game))
         .route("/games/:id/moves", get(list_legal_moves).post(apply_move))
+        .route("/games/:id/pgn", get(export_pgn))
+        .route("/games/import-pgn", post(import_pgn))
+        .route("/games/:id/bestmove", post(bestmove))
+        .route("/games/:id/undo", post(undo_move))
+        .route("/lobby", get(lobby))
+        .route("/games/:id/ws", get(game_socket))
         .with_state(state)
         .layer(CompressionLayer::new())
         .layer(DecompressionLayer::new())
@@ -184,13 +474,14 @@ async fn create_game(
 
     let fen = fen_of(&pos);
 
-    let entry = GameEntry {
-        pos,
-        history_uci: Vec::new(),
-        history_san: Vec::new(),
-    };
+    let clock = req.time_control.map(|tc| {
+        Clock::new(
+            Duration::from_secs(tc.base_secs),
+            Duration::from_secs(tc.increment_secs),
+        )
+    });
 
-    state.store.write().await.insert(id, entry);
+    state.store.write().await.insert(id, GameEntry::new(pos, clock));
 
     Ok(Json(CreateGameResponse { id, fen }))
 }
@@ -199,8 +490,10 @@ async fn get_game(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<GameResponse>, ApiError> {
-    let store = state.store.read().await;
-    let entry = store.get(&id).ok_or(ApiError::NotFound)?;
+    let mut store = state.store.write().await;
+    let entry = store.get_mut(&id).ok_or(ApiError::NotFound)?;
+
+    apply_time_forfeit_if_expired(entry);
 
     Ok(Json(game_response(id, entry)))
 }
@@ -233,33 +526,134 @@ async fn apply_move(
     Path(id): Path<Uuid>,
     Json(req): Json<ApplyMoveRequest>,
 ) -> Result<Json<ApplyMoveResponse>, ApiError> {
-    let mut store = state.store.write().await;
-    let entry = store.get_mut(&id).ok_or(ApiError::NotFound)?;
+    // Validating and playing the caller's move needs the write lock; the engine auto-reply
+    // search below does not, and must not hold it while it runs (see that comment).
+    let san = {
+        let mut store = state.store.write().await;
+        let entry = store.get_mut(&id).ok_or(ApiError::NotFound)?;
 
-    // Parse UCI move
-    let uci: UciMove = req
-        .uci
-        .parse()
-        .map_err(|e| ApiError::BadRequest(format!("invalid UCI: {e}")))?;
+        apply_time_forfeit_if_expired(entry);
+        if entry.forfeit.is_some() {
+            return Err(ApiError::IllegalMove("game over: time forfeit".to_string()));
+        }
 
-    // Convert UCI to a legal move for current position
-    let m: Move = uci
-        .to_move(&entry.pos)
-        .map_err(|e| ApiError::IllegalMove(format!("{e}")))?;
+        // Once both seats have been claimed this is a multiplayer game, and a valid token for
+        // the seat whose turn it is becomes mandatory — otherwise either side could move as the
+        // other.
+        if entry.seats.is_paired() {
+            let token = req
+                .token
+                .ok_or_else(|| ApiError::BadRequest("reconnect token required".to_string()))?;
+            let color = entry
+                .seats
+                .color_for_token(token)
+                .ok_or_else(|| ApiError::BadRequest("invalid reconnect token".to_string()))?;
+            if color != entry.pos.turn() {
+                return Err(ApiError::IllegalMove("not this seat's turn".to_string()));
+            }
+        }
 
-    // Convert to SAN for history before playing
-    let san = San::from_move(&entry.pos, m).to_string();
+        // Parse UCI move
+        let uci: UciMove = req
+            .uci
+            .parse()
+            .map_err(|e| ApiError::BadRequest(format!("invalid UCI: {e}")))?;
 
-    // Play the move
-    // Ensure to update the position; play_unchecked mutates in-place for Chess.
-    entry.pos.play_unchecked(m);
+        // Convert UCI to a legal move for current position
+        let m: Move = uci
+            .to_move(&entry.pos)
+            .map_err(|e| ApiError::IllegalMove(format!("{e}")))?;
 
-    let fen = fen_of(&entry.pos);
-    entry.history_uci.push(uci.to_string());
-    entry.history_san.push(san.clone());
+        // Convert to SAN for history before playing
+        let san = San::from_move(&entry.pos, &m).to_string();
+
+        // Play the move
+        // Ensure to update the position; play_unchecked mutates in-place for Chess.
+        let mover = entry.pos.turn();
+        entry.history_pos.push(entry.pos.clone());
+        entry.pos.play_unchecked(&m);
+
+        entry.history_uci.push(uci.to_string());
+        entry.history_san.push(san.clone());
 
-    let status = status_of(&entry.pos);
+        if let Some(clock) = &mut entry.clock {
+            clock.record_move(mover);
+        }
+
+        let _ = entry.updates.send(GameUpdate {
+            uci: Some(uci.to_string()),
+            san: Some(san.clone()),
+            fen: fen_of(&entry.pos),
+            status: status_of_entry(entry),
+        });
+
+        san
+    };
+
+    // Optionally let the engine answer immediately, so the response already reflects both plies.
+    // Negamax at AUTO_REPLY_DEPTH can still take a while, so the search runs via spawn_blocking
+    // with the store lock released, rather than inline while holding it — otherwise one slow
+    // search would freeze every other game's moves, creates, and undos for its duration.
+    let engine_reply = if req.auto_reply {
+        let pos_to_search = {
+            let store = state.store.read().await;
+            store.get(&id).ok_or(ApiError::NotFound)?.pos.clone()
+        };
+
+        let search_pos = pos_to_search.clone();
+        let best = tokio::task::spawn_blocking(move || search_best_move(&search_pos, AUTO_REPLY_DEPTH))
+            .await
+            .expect("search task panicked");
+
+        match best {
+            Some((engine_move, _score)) => {
+                let mut store = state.store.write().await;
+                let entry = store.get_mut(&id).ok_or(ApiError::NotFound)?;
+
+                // Another request (the opponent's own move, an undo...) may have changed the
+                // position while the search ran with the lock released; only play the reply if
+                // it's still the position it was computed for.
+                if entry.pos == pos_to_search {
+                    let engine_uci = engine_move.to_uci(entry.pos.castles().mode()).to_string();
+                    let engine_san = San::from_move(&entry.pos, &engine_move).to_string();
+
+                    let engine_mover = entry.pos.turn();
+                    entry.history_pos.push(entry.pos.clone());
+                    entry.pos.play_unchecked(&engine_move);
+                    entry.history_uci.push(engine_uci.clone());
+                    entry.history_san.push(engine_san.clone());
+
+                    if let Some(clock) = &mut entry.clock {
+                        clock.record_move(engine_mover);
+                    }
+
+                    let _ = entry.updates.send(GameUpdate {
+                        uci: Some(engine_uci.clone()),
+                        san: Some(engine_san.clone()),
+                        fen: fen_of(&entry.pos),
+                        status: status_of_entry(entry),
+                    });
+
+                    Some(EngineMove {
+                        uci: engine_uci,
+                        san: engine_san,
+                    })
+                } else {
+                    None
+                }
+            }
+            None => None,
+        }
+    } else {
+        None
+    };
+
+    let store = state.store.read().await;
+    let entry = store.get(&id).ok_or(ApiError::NotFound)?;
+    let fen = fen_of(&entry.pos);
+    let status = status_of_entry(entry);
     let legal_moves = legal_moves_uci(&entry.pos);
+    let clock = entry.clock.as_ref().map(|c| clock_view(c, entry.pos.turn()));
 
     Ok(Json(ApplyMoveResponse {
         id,
@@ -268,9 +662,470 @@ async fn apply_move(
         fen,
         legal_moves,
         status,
+        engine_reply,
+        clock,
     }))
 }
 
+async fn bestmove(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<BestMoveRequest>,
+) -> Result<Json<BestMoveResponse>, ApiError> {
+    let pos = {
+        let store = state.store.read().await;
+        store.get(&id).ok_or(ApiError::NotFound)?.pos.clone()
+    };
+
+    // Negamax at MAX_SEARCH_DEPTH can take seconds; run it off the async runtime so it doesn't
+    // pin a tokio worker, and with the store lock already released above, so it can't stall
+    // every other game's moves while it runs.
+    let depth = req.depth.clamp(1, MAX_SEARCH_DEPTH);
+    let search_pos = pos.clone();
+    let (m, score) = tokio::task::spawn_blocking(move || search_best_move(&search_pos, depth))
+        .await
+        .expect("search task panicked")
+        .ok_or_else(|| ApiError::BadRequest("no legal moves in this position".to_string()))?;
+
+    let uci = m.to_uci(pos.castles().mode()).to_string();
+    let san = San::from_move(&pos, &m).to_string();
+
+    Ok(Json(BestMoveResponse { uci, san, score }))
+}
+
+async fn undo_move(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UndoRequest>,
+) -> Result<Json<GameResponse>, ApiError> {
+    let mut store = state.store.write().await;
+    let entry = store.get_mut(&id).ok_or(ApiError::NotFound)?;
+
+    let plies = req.plies.unwrap_or(1).max(1) as usize;
+    if plies > entry.history_pos.len() {
+        return Err(ApiError::BadRequest(format!(
+            "cannot undo {plies} ply(s): only {} played",
+            entry.history_pos.len()
+        )));
+    }
+
+    let restore_at = entry.history_pos.len() - plies;
+    entry.pos = entry.history_pos[restore_at].clone();
+    entry.history_pos.truncate(restore_at);
+    entry.history_uci.truncate(restore_at);
+    entry.history_san.truncate(restore_at);
+    entry.forfeit = None;
+
+    // The restored position may belong to either side; just restart its turn timer rather than
+    // trying to reconstruct how much time it had left mid-turn.
+    if let Some(clock) = &mut entry.clock {
+        clock.turn_started = Instant::now();
+    }
+
+    // Let connected `/games/:id/ws` sockets know the position rolled back; there's no single
+    // move to report, so uci/san are left unset.
+    let _ = entry.updates.send(GameUpdate {
+        uci: None,
+        san: None,
+        fen: fen_of(&entry.pos),
+        status: status_of_entry(entry),
+    });
+
+    Ok(Json(game_response(id, entry)))
+}
+
+// Negamax with alpha-beta pruning over shakmaty positions, scored from the side-to-move's
+// perspective. Returns the best legal move and its score, or `None` if there are none.
+fn search_best_move(pos: &Chess, depth: u32) -> Option<(Move, i32)> {
+    let mut melhor: Option<(Move, i32)> = None;
+    let mut alpha = -MATE_SCORE - 1;
+    let beta = MATE_SCORE + 1;
+
+    for m in pos.legal_moves() {
+        let mut seguinte = pos.clone();
+        seguinte.play_unchecked(&m);
+
+        let score = -negamax(&seguinte, depth.saturating_sub(1), 1, -beta, -alpha);
+        let is_better = match &melhor {
+            Some((_, melhor_score)) => score > *melhor_score,
+            None => true,
+        };
+        if is_better {
+            melhor = Some((m, score));
+        }
+        if score > alpha {
+            alpha = score;
+        }
+    }
+
+    melhor
+}
+
+fn negamax(pos: &Chess, depth: u32, ply: u32, mut alpha: i32, beta: i32) -> i32 {
+    if let Some(outcome) = pos.outcome() {
+        return match outcome {
+            // The side to move here has no legal moves and is in check: a loss for them, scored
+            // so that shorter mates (found at a shallower ply) are preferred over longer ones.
+            Outcome::Decisive { .. } => ply as i32 - MATE_SCORE,
+            Outcome::Draw => 0,
+        };
+    }
+
+    if depth == 0 {
+        return evaluate(pos);
+    }
+
+    let mut melhor = -MATE_SCORE - 1;
+    for m in pos.legal_moves() {
+        let mut seguinte = pos.clone();
+        seguinte.play_unchecked(&m);
+
+        let score = -negamax(&seguinte, depth - 1, ply + 1, -beta, -alpha);
+        if score > melhor {
+            melhor = score;
+        }
+        if score > alpha {
+            alpha = score;
+        }
+        if alpha >= beta {
+            break;
+        }
+    }
+
+    melhor
+}
+
+// Material balance plus a small mobility term, scored from the side-to-move's perspective.
+fn evaluate(pos: &Chess) -> i32 {
+    let material = material_balance(pos);
+    let sign = if pos.turn() == Color::White { 1 } else { -1 };
+
+    let my_mobility = pos.legal_moves().len() as i32;
+    let opponent_mobility = pos
+        .clone()
+        .swap_turn()
+        .map(|swapped| swapped.legal_moves().len() as i32)
+        .unwrap_or(my_mobility);
+
+    sign * material + (my_mobility - opponent_mobility)
+}
+
+fn material_balance(pos: &Chess) -> i32 {
+    use shakmaty::Role;
+
+    const VALORES: [(Role, i32); 5] = [
+        (Role::Pawn, 100),
+        (Role::Knight, 320),
+        (Role::Bishop, 330),
+        (Role::Rook, 500),
+        (Role::Queen, 900),
+    ];
+
+    let board = pos.board();
+    VALORES
+        .iter()
+        .map(|&(role, value)| {
+            let white = (board.by_color(Color::White) & board.by_role(role)).count() as i32;
+            let black = (board.by_color(Color::Black) & board.by_role(role)).count() as i32;
+            value * (white - black)
+        })
+        .sum()
+}
+
+// Pairs two waiting clients into a fresh game. The first caller blocks until a second one
+// connects; the second caller creates the game and wakes the first one up.
+async fn lobby(ws: WebSocketUpgrade, State(state): State<AppState>) -> Response {
+    ws.on_upgrade(move |socket| handle_lobby_socket(socket, state))
+}
+
+async fn handle_lobby_socket(socket: WebSocket, state: AppState) {
+    // Hold a single lock across the take-or-insert decision: two separate `lock().await` calls
+    // here would let two concurrent callers both observe an empty lobby and each insert their
+    // own waiter, with the second silently dropping (and thus never notifying) the first.
+    let mut lobby = state.lobby.lock().await;
+    let existing_waiter = lobby.take();
+
+    match existing_waiter {
+        Some(waiter) => {
+            drop(lobby);
+
+            let game_id = Uuid::new_v4();
+            let entry = GameEntry::new(Chess::default(), None);
+            let white_token = entry.seats.seat(Color::White).reconnect_token;
+            let black_token = entry.seats.seat(Color::Black).reconnect_token;
+            state.store.write().await.insert(game_id, entry);
+
+            // If the first caller already gave up waiting, just leave this seat open; a later
+            // direct connection to `/games/:id/ws` can still claim it with `black_token`.
+            let _ = waiter.notify.send(LobbyMatch {
+                game_id,
+                color: Color::White,
+                reconnect_token: white_token,
+            });
+
+            run_game_socket(socket, state, game_id, Color::Black, black_token).await;
+        }
+        None => {
+            let (notify, matched) = oneshot::channel();
+            *lobby = Some(LobbyWaiter { notify });
+            drop(lobby);
+
+            if let Ok(m) = matched.await {
+                run_game_socket(socket, state, m.game_id, m.color, m.reconnect_token).await;
+            }
+        }
+    }
+}
+
+// Joins (or resumes) a seat in an existing game and streams `GameUpdate`s to it in real time.
+async fn game_socket(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<WsJoinQuery>,
+) -> Result<Response, ApiError> {
+    let color = {
+        let store = state.store.read().await;
+        let entry = store.get(&id).ok_or(ApiError::NotFound)?;
+        entry
+            .seats
+            .color_for_token(query.token)
+            .ok_or_else(|| ApiError::BadRequest("invalid reconnect token".to_string()))?
+    };
+
+    Ok(ws.on_upgrade(move |socket| run_game_socket(socket, state, id, color, query.token)))
+}
+
+async fn run_game_socket(mut socket: WebSocket, state: AppState, id: Uuid, color: Color, token: Uuid) {
+    let snapshot = {
+        let mut store = state.store.write().await;
+        let entry = match store.get_mut(&id) {
+            Some(entry) => entry,
+            None => return,
+        };
+        entry.seats.seat_mut(color).status = PlayerStatus::Connected;
+        GameSocketSnapshot {
+            game_id: id,
+            color: side_to_move_of(color),
+            reconnect_token: token,
+            game: game_response(id, entry),
+        }
+    };
+
+    let mut updates = {
+        let store = state.store.read().await;
+        match store.get(&id) {
+            Some(entry) => entry.updates.subscribe(),
+            None => return,
+        }
+    };
+
+    let payload = serde_json::to_string(&snapshot).unwrap_or_default();
+    if socket.send(Message::Text(payload)).await.is_err() {
+        hold_seat_for_reconnect(&state, id, color).await;
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            update = updates.recv() => {
+                match update {
+                    Ok(update) => {
+                        let payload = serde_json::to_string(&update).unwrap_or_default();
+                        if socket.send(Message::Text(payload)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+            incoming = socket.recv() => {
+                match incoming {
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Err(_)) => break,
+                    // Moves are applied through the REST API; inbound socket chatter is ignored.
+                    Some(Ok(_)) => {}
+                }
+            }
+        }
+    }
+
+    // Hold the seat open so the same token can resume and catch up via the snapshot above.
+    hold_seat_for_reconnect(&state, id, color).await;
+}
+
+// Marks `color`'s seat `Reconnecting` and bounds how long the game stays open waiting for it:
+// if the seat is still on the same reconnect attempt after `RECONNECT_GRACE`, the game is
+// forfeited to the other side. A subsequent `game_socket` call that resumes the seat (setting it
+// `Connected` again) makes the timeout a no-op when it eventually fires; so does a *later* call
+// to this function, which bumps `reconnect_generation` and leaves this timer watching a stale
+// attempt — otherwise a flapping connection (disconnect, reconnect, disconnect again) would let
+// the first disconnect's timer forfeit the game well before a fresh `RECONNECT_GRACE` has passed.
+async fn hold_seat_for_reconnect(state: &AppState, id: Uuid, color: Color) {
+    let generation = {
+        let mut store = state.store.write().await;
+        let Some(entry) = store.get_mut(&id) else {
+            return;
+        };
+        let seat = entry.seats.seat_mut(color);
+        seat.status = PlayerStatus::Reconnecting;
+        seat.reconnect_generation += 1;
+        seat.reconnect_generation
+    };
+
+    let state = state.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(RECONNECT_GRACE).await;
+
+        let mut store = state.store.write().await;
+        let Some(entry) = store.get_mut(&id) else {
+            return;
+        };
+        let seat = entry.seats.seat(color);
+        let game_status = status_of_entry(entry);
+        if !should_forfeit_on_reconnect_timeout(
+            generation,
+            seat.reconnect_generation,
+            seat.status,
+            &game_status,
+        ) {
+            return;
+        }
+
+        entry.forfeit = Some(side_to_move_of(color.other()));
+        let _ = entry.updates.send(GameUpdate {
+            uci: None,
+            san: None,
+            fen: fen_of(&entry.pos),
+            status: status_of_entry(entry),
+        });
+    });
+}
+
+// Whether a reconnect-grace timer armed while the seat's generation was `timer_generation`
+// should still forfeit the game: false once a later disconnect has bumped the seat past that
+// generation, once the seat has actually reconnected, or once the game already ended some other
+// way.
+fn should_forfeit_on_reconnect_timeout(
+    timer_generation: u64,
+    current_generation: u64,
+    seat_status: PlayerStatus,
+    game_status: &GameStatus,
+) -> bool {
+    timer_generation == current_generation
+        && matches!(seat_status, PlayerStatus::Reconnecting)
+        && matches!(game_status, GameStatus::Ongoing { .. })
+}
+
+async fn export_pgn(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<String, ApiError> {
+    let store = state.store.read().await;
+    let entry = store.get(&id).ok_or(ApiError::NotFound)?;
+
+    Ok(pgn_of(entry))
+}
+
+async fn import_pgn(
+    State(state): State<AppState>,
+    Json(req): Json<ImportPgnRequest>,
+) -> Result<Json<CreateGameResponse>, ApiError> {
+    let mut pos = Chess::default();
+    let mut history_uci = Vec::new();
+    let mut history_san = Vec::new();
+    let mut history_pos = Vec::new();
+
+    for token in movetext_tokens(&req.pgn) {
+        let san = San::from_ascii(token.as_bytes())
+            .map_err(|e| ApiError::BadRequest(format!("invalid SAN '{token}': {e}")))?;
+        let m = san
+            .to_move(&pos)
+            .map_err(|e| ApiError::BadRequest(format!("illegal move '{token}': {e}")))?;
+        let uci = m.to_uci(pos.castles().mode());
+
+        history_pos.push(pos.clone());
+        pos.play_unchecked(&m);
+        history_uci.push(uci.to_string());
+        history_san.push(token);
+    }
+
+    let fen = fen_of(&pos);
+    let id = Uuid::new_v4();
+    let mut entry = GameEntry::new(pos, None);
+    entry.history_uci = history_uci;
+    entry.history_san = history_san;
+    entry.history_pos = history_pos;
+
+    state.store.write().await.insert(id, entry);
+
+    Ok(Json(CreateGameResponse { id, fen }))
+}
+
+// Renders the Seven Tag Roster header block plus numbered movetext, e.g.:
+// "1. e4 e5 2. Nf3 Nc6 *"
+fn pgn_of(entry: &GameEntry) -> String {
+    let result = result_tag(&status_of_entry(entry));
+
+    let mut pgn = String::new();
+    pgn.push_str("[Event \"Casual Game\"]\n");
+    pgn.push_str("[Site \"chess-rust\"]\n");
+    pgn.push_str("[Date \"????.??.??\"]\n");
+    pgn.push_str("[Round \"1\"]\n");
+    pgn.push_str("[White \"?\"]\n");
+    pgn.push_str("[Black \"?\"]\n");
+    pgn.push_str(&format!("[Result \"{result}\"]\n"));
+    pgn.push('\n');
+
+    for (i, san) in entry.history_san.iter().enumerate() {
+        if i % 2 == 0 {
+            pgn.push_str(&format!("{}. ", i / 2 + 1));
+        }
+        pgn.push_str(san);
+        pgn.push(' ');
+    }
+    pgn.push_str(result);
+    pgn.push('\n');
+
+    pgn
+}
+
+fn result_tag(status: &GameStatus) -> &'static str {
+    match status {
+        GameStatus::Checkmate {
+            winner: SideToMove::White,
+        } => "1-0",
+        GameStatus::Checkmate {
+            winner: SideToMove::Black,
+        } => "0-1",
+        GameStatus::TimeForfeit {
+            winner: SideToMove::White,
+        } => "1-0",
+        GameStatus::TimeForfeit {
+            winner: SideToMove::Black,
+        } => "0-1",
+        GameStatus::Stalemate | GameStatus::Draw => "1/2-1/2",
+        GameStatus::Ongoing { .. } => "*",
+    }
+}
+
+// Strips header tags and move numbers from a PGN document, returning the bare SAN tokens.
+fn movetext_tokens(pgn: &str) -> Vec<String> {
+    let movetext: String = pgn
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('['))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    movetext
+        .split_whitespace()
+        .filter(|tok| !matches!(*tok, "1-0" | "0-1" | "1/2-1/2" | "*"))
+        .map(|tok| tok.trim_start_matches(|c: char| c.is_ascii_digit() || c == '.'))
+        .filter(|tok| !tok.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 fn game_response(id: Uuid, entry: &GameEntry) -> GameResponse {
     GameResponse {
         id,
@@ -278,7 +1133,12 @@ fn game_response(id: Uuid, entry: &GameEntry) -> GameResponse {
         legal_moves: legal_moves_uci(&entry.pos),
         moves_uci: entry.history_uci.clone(),
         moves_san: entry.history_san.clone(),
-        status: status_of(&entry.pos),
+        status: status_of_entry(entry),
+        seats: SeatsSummary {
+            white: entry.seats.seat(Color::White).status,
+            black: entry.seats.seat(Color::Black).status,
+        },
+        clock: entry.clock.as_ref().map(|c| clock_view(c, entry.pos.turn())),
     }
 }
 
@@ -292,45 +1152,78 @@ fn legal_moves_uci(pos: &Chess) -> Vec<String> {
 
 fn status_of(pos: &Chess) -> GameStatus {
     match pos.outcome() {
-        shakmaty::Outcome::Known(known) => match known {
-            shakmaty::KnownOutcome::Decisive { winner } => match winner {
-                Color::White => GameStatus::Checkmate {
-                    winner: SideToMove::White,
-                },
-                Color::Black => GameStatus::Checkmate {
-                    winner: SideToMove::Black,
-                },
-            },
-            shakmaty::KnownOutcome::Draw => {
-                if pos.is_stalemate() {
-                    GameStatus::Stalemate
-                } else {
-                    GameStatus::Draw
-                }
-            }
+        Some(Outcome::Decisive { winner }) => GameStatus::Checkmate {
+            winner: side_to_move_of(winner),
         },
-        shakmaty::Outcome::Unknown => GameStatus::Ongoing {
-            to_move: match side_to_move(pos) {
-                Color::White => SideToMove::White,
-                Color::Black => SideToMove::Black,
-            },
+        Some(Outcome::Draw) => {
+            if pos.is_stalemate() {
+                GameStatus::Stalemate
+            } else {
+                GameStatus::Draw
+            }
+        }
+        None => GameStatus::Ongoing {
+            to_move: side_to_move_of(side_to_move(pos)),
             in_check: pos.is_check(),
         },
     }
 }
 
+// A game's status, with a persisted time forfeit taking priority over the position's own outcome.
+fn status_of_entry(entry: &GameEntry) -> GameStatus {
+    if let Some(winner) = entry.forfeit {
+        return GameStatus::TimeForfeit { winner };
+    }
+    status_of(&entry.pos)
+}
+
+// Checks the side to move's clock against the elapsed time since their turn began, persisting a
+// `TimeForfeit` once it has run out. A no-op for untimed games or games already decided.
+fn apply_time_forfeit_if_expired(entry: &mut GameEntry) {
+    if entry.forfeit.is_some() {
+        return;
+    }
+    let Some(clock) = &entry.clock else {
+        return;
+    };
+    if !matches!(status_of(&entry.pos), GameStatus::Ongoing { .. }) {
+        return;
+    }
+
+    let active = entry.pos.turn();
+    if clock.is_expired(active) {
+        entry.forfeit = Some(side_to_move_of(active.other()));
+    }
+}
+
+// Remaining time per side as of now, charging the in-flight turn against whoever is to move.
+fn clock_view(clock: &Clock, active: Color) -> ClockView {
+    ClockView {
+        white_remaining_secs: clock.remaining_now(Color::White, active).as_secs(),
+        black_remaining_secs: clock.remaining_now(Color::Black, active).as_secs(),
+        increment_secs: clock.increment.as_secs(),
+    }
+}
+
 fn side_to_move(pos: &Chess) -> Color {
     // Using a trick: if any legal move exists for White when pos is white to move, but we can directly access via pos.turn()
     // Shakmaty exposes the side to move via pos.turn().
     pos.turn()
 }
 
+fn side_to_move_of(color: Color) -> SideToMove {
+    match color {
+        Color::White => SideToMove::White,
+        Color::Black => SideToMove::Black,
+    }
+}
+
 fn fen_of(pos: &Chess) -> String {
     // Prefer a legal en-passant encoding for a precise state.
     // Fen implements Display.
     // This uses the default "Legal" en passant mode to not include pseudo squares.
     use shakmaty::{EnPassantMode, fen::Fen};
-    Fen::from_position(pos, EnPassantMode::Legal).to_string()
+    Fen::from_position(pos.clone(), EnPassantMode::Legal).to_string()
 }
 
 // ----- Tests (basic) -----
@@ -347,15 +1240,140 @@ mod tests {
 
     #[test]
     fn uci_parsing_and_play() {
-        let mut entry = GameEntry {
-            pos: Chess::default(),
-            history_uci: vec![],
-            history_san: vec![],
-        };
+        let mut entry = GameEntry::new(Chess::default(), None);
         let uci: UciMove = "e2e4".parse().expect("uci");
         let m = uci.to_move(&entry.pos).expect("legal");
-        entry.pos.play_unchecked(m);
+        entry.pos.play_unchecked(&m);
         assert_eq!(entry.history_uci.len(), 0);
         assert_eq!(entry.pos.legal_moves().len(), 20); // after e4, still many moves
     }
+
+    #[test]
+    fn pgn_of_fresh_game_has_header_and_star_result() {
+        let entry = GameEntry::new(Chess::default(), None);
+        let pgn = pgn_of(&entry);
+        assert!(pgn.contains("[Event \"Casual Game\"]"));
+        assert!(pgn.trim_end().ends_with('*'));
+    }
+
+    #[test]
+    fn movetext_tokens_strips_headers_and_move_numbers() {
+        let pgn = "[Event \"Test\"]\n[Result \"*\"]\n\n1. e4 e5 2. Nf3 Nc6 *";
+        let tokens = movetext_tokens(pgn);
+        assert_eq!(tokens, vec!["e4", "e5", "Nf3", "Nc6"]);
+    }
+
+    #[test]
+    fn record_move_charges_mover_and_adds_increment() {
+        let mut clock = Clock::new(Duration::from_secs(60), Duration::from_secs(2));
+        clock.record_move(Color::White);
+        // Only a sliver of wall-clock time elapses between `new` and `record_move`, so White
+        // should gain the increment minus at most a few milliseconds of that.
+        let white_remaining = clock.remaining(Color::White);
+        assert!(white_remaining <= Duration::from_secs(62));
+        assert!(white_remaining > Duration::from_millis(61_900));
+        assert_eq!(clock.remaining(Color::Black), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn expired_clock_is_forfeit_only_for_the_side_to_move() {
+        let mut clock = Clock::new(Duration::from_secs(60), Duration::from_secs(0));
+        clock.white_remaining = Duration::ZERO;
+        assert!(clock.is_expired(Color::White));
+        assert!(!clock.is_expired(Color::Black));
+    }
+
+    #[test]
+    fn undo_restores_position_and_truncates_history() {
+        let mut entry = GameEntry::new(Chess::default(), None);
+        for uci_str in ["e2e4", "e7e5"] {
+            let uci: UciMove = uci_str.parse().expect("uci");
+            let m = uci.to_move(&entry.pos).expect("legal");
+            entry.history_pos.push(entry.pos.clone());
+            entry.pos.play_unchecked(&m);
+            entry.history_uci.push(uci.to_string());
+            entry.history_san.push(uci_str.to_string());
+        }
+
+        let restore_at = entry.history_pos.len() - 1;
+        entry.pos = entry.history_pos[restore_at].clone();
+        entry.history_pos.truncate(restore_at);
+        entry.history_uci.truncate(restore_at);
+        entry.history_san.truncate(restore_at);
+
+        assert_eq!(entry.history_uci, vec!["e2e4"]);
+        assert_eq!(entry.pos.turn(), Color::Black);
+    }
+
+    #[test]
+    fn seats_are_not_paired_until_both_are_claimed() {
+        let mut seats = Seats::new();
+        assert!(!seats.is_paired());
+
+        seats.seat_mut(Color::White).status = PlayerStatus::Connected;
+        assert!(!seats.is_paired());
+
+        seats.seat_mut(Color::Black).status = PlayerStatus::Connected;
+        assert!(seats.is_paired());
+    }
+
+    #[test]
+    fn color_for_token_looks_up_the_owning_seat() {
+        let seats = Seats::new();
+        let white_token = seats.seat(Color::White).reconnect_token;
+        let black_token = seats.seat(Color::Black).reconnect_token;
+
+        assert_eq!(seats.color_for_token(white_token), Some(Color::White));
+        assert_eq!(seats.color_for_token(black_token), Some(Color::Black));
+        assert_eq!(seats.color_for_token(Uuid::new_v4()), None);
+    }
+
+    fn ongoing() -> GameStatus {
+        GameStatus::Ongoing {
+            to_move: SideToMove::White,
+            in_check: false,
+        }
+    }
+
+    #[test]
+    fn reconnect_timeout_forfeits_when_nothing_changed_since_it_was_armed() {
+        assert!(should_forfeit_on_reconnect_timeout(
+            1,
+            1,
+            PlayerStatus::Reconnecting,
+            &ongoing(),
+        ));
+    }
+
+    #[test]
+    fn reconnect_timeout_is_stale_once_a_later_disconnect_rearmed_it() {
+        // A flapping connection bumps the generation past what this timer was armed with; it
+        // should defer to the fresh timer rather than forfeiting early.
+        assert!(!should_forfeit_on_reconnect_timeout(
+            1,
+            2,
+            PlayerStatus::Reconnecting,
+            &ongoing(),
+        ));
+    }
+
+    #[test]
+    fn reconnect_timeout_is_a_no_op_once_the_seat_reconnected() {
+        assert!(!should_forfeit_on_reconnect_timeout(
+            1,
+            1,
+            PlayerStatus::Connected,
+            &ongoing(),
+        ));
+    }
+
+    #[test]
+    fn reconnect_timeout_is_a_no_op_once_the_game_already_ended() {
+        assert!(!should_forfeit_on_reconnect_timeout(
+            1,
+            1,
+            PlayerStatus::Reconnecting,
+            &GameStatus::Stalemate,
+        ));
+    }
 }
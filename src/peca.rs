@@ -0,0 +1,61 @@
+use crate::jogador::Jogador;
+use crate::posicao::Posicao;
+use crate::tabuleiro::Tabuleiro;
+
+pub mod bispo;
+pub mod cavalo;
+pub mod peao;
+pub mod rainha;
+pub mod rei;
+pub mod torre;
+
+#[derive(Clone)]
+pub struct PecaData {
+    pub jogador: Jogador,
+    pub nome: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TipoPeca {
+    Peao,
+    Cavalo,
+    Bispo,
+    Torre,
+    Rainha,
+    Rei,
+}
+
+pub trait Peca {
+    fn dados(&self) -> &PecaData;
+    fn tipo(&self) -> TipoPeca;
+
+    // Pseudo-legal moves for this piece from `posicao`, ignoring whether they leave the
+    // mover's own king in check — `Tabuleiro::movimentos_legais` applies that filter.
+    fn possiveis_movimentos(&self, tabuleiro: &Tabuleiro, posicao: &Posicao) -> Vec<Posicao>;
+
+    // Squares this piece threatens, independent of whether there is actually something to
+    // capture there — used by `Tabuleiro::posicao_atacada` for check and castling-through-check
+    // detection. Defaults to the piece's pseudo-legal moves; pawns override this, since their
+    // diagonals are normally gated on occupancy in `possiveis_movimentos`.
+    fn casas_atacadas(&self, tabuleiro: &Tabuleiro, posicao: &Posicao) -> Vec<Posicao> {
+        self.possiveis_movimentos(tabuleiro, posicao)
+    }
+
+    fn caixa_clone(&self) -> Box<dyn Peca>;
+
+    fn mover(&self, tabuleiro: &Tabuleiro, de: &Posicao, para: &Posicao) -> bool {
+        tabuleiro.movimentos_legais(de).contains(para)
+    }
+}
+
+// Builds a fresh piece of `tipo` for `jogador`, for `Tabuleiro::aplicar_movimento` to swap a pawn
+// into on promotion. Panics for `Peao`/`Rei`, which are never legal promotion targets.
+pub fn criar_peca_promovida(tipo: TipoPeca, jogador: Jogador) -> Box<dyn Peca> {
+    match tipo {
+        TipoPeca::Cavalo => Box::new(cavalo::Cavalo::new(jogador)),
+        TipoPeca::Bispo => Box::new(bispo::Bispo::new(jogador)),
+        TipoPeca::Torre => Box::new(torre::Torre::new(jogador)),
+        TipoPeca::Rainha => Box::new(rainha::Rainha::new(jogador)),
+        TipoPeca::Peao | TipoPeca::Rei => panic!("{tipo:?} is not a legal promotion target"),
+    }
+}
@@ -0,0 +1,44 @@
+use crate::jogador::Jogador;
+use crate::peca::{Peca, PecaData, TipoPeca};
+use crate::posicao::Posicao;
+use crate::tabuleiro::Tabuleiro;
+
+pub struct Bispo {
+    pub data: PecaData,
+}
+
+impl Bispo {
+    pub fn new(jogador: Jogador) -> Self {
+        Bispo {
+            data: PecaData {
+                jogador,
+                nome: "Bispo".to_string(),
+            },
+        }
+    }
+}
+
+const DIRECOES: [(i32, i32); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+impl Peca for Bispo {
+    fn dados(&self) -> &PecaData {
+        &self.data
+    }
+
+    fn tipo(&self) -> TipoPeca {
+        TipoPeca::Bispo
+    }
+
+    fn possiveis_movimentos(&self, tabuleiro: &Tabuleiro, posicao: &Posicao) -> Vec<Posicao> {
+        DIRECOES
+            .iter()
+            .flat_map(|&direcao| tabuleiro.raio(posicao, direcao, self.data.jogador))
+            .collect()
+    }
+
+    fn caixa_clone(&self) -> Box<dyn Peca> {
+        Box::new(Bispo {
+            data: self.data.clone(),
+        })
+    }
+}
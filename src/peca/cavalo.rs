@@ -0,0 +1,57 @@
+use crate::jogador::Jogador;
+use crate::peca::{Peca, PecaData, TipoPeca};
+use crate::posicao::Posicao;
+use crate::tabuleiro::Tabuleiro;
+
+pub struct Cavalo {
+    pub data: PecaData,
+}
+
+impl Cavalo {
+    pub fn new(jogador: Jogador) -> Self {
+        Cavalo {
+            data: PecaData {
+                jogador,
+                nome: "Cavalo".to_string(),
+            },
+        }
+    }
+}
+
+const SALTOS: [(i32, i32); 8] = [
+    (1, 2),
+    (2, 1),
+    (-1, 2),
+    (-2, 1),
+    (1, -2),
+    (2, -1),
+    (-1, -2),
+    (-2, -1),
+];
+
+impl Peca for Cavalo {
+    fn dados(&self) -> &PecaData {
+        &self.data
+    }
+
+    fn tipo(&self) -> TipoPeca {
+        TipoPeca::Cavalo
+    }
+
+    fn possiveis_movimentos(&self, tabuleiro: &Tabuleiro, posicao: &Posicao) -> Vec<Posicao> {
+        SALTOS
+            .iter()
+            .filter_map(|&(delta_linha, delta_coluna)| posicao.deslocar(delta_linha, delta_coluna))
+            .filter(|destino| match tabuleiro.peca_em(destino) {
+                Some(peca) => peca.dados().jogador != self.data.jogador,
+                None => true,
+            })
+            .collect()
+    }
+
+    fn caixa_clone(&self) -> Box<dyn Peca> {
+        Box::new(Cavalo {
+            data: self.data.clone(),
+        })
+    }
+}
@@ -0,0 +1,91 @@
+use crate::jogador::Jogador;
+use crate::peca::{Peca, PecaData, TipoPeca};
+use crate::posicao::{Linha, Posicao};
+use crate::tabuleiro::Tabuleiro;
+
+pub struct Peao {
+    pub data: PecaData,
+}
+
+impl Peao {
+    pub fn new(jogador: Jogador) -> Self {
+        let p_data: PecaData = PecaData {
+            jogador,
+            nome: "Peão".to_string(),
+        };
+        Peao { data: p_data }
+    }
+
+    fn linha_inicial(&self) -> Linha {
+        match self.data.jogador {
+            Jogador::Branco => Linha::B,
+            Jogador::Preto => Linha::G,
+        }
+    }
+
+    fn direcao(&self) -> i32 {
+        match self.data.jogador {
+            Jogador::Branco => 1,
+            Jogador::Preto => -1,
+        }
+    }
+}
+
+impl Peca for Peao {
+    fn dados(&self) -> &PecaData {
+        &self.data
+    }
+
+    fn tipo(&self) -> TipoPeca {
+        TipoPeca::Peao
+    }
+
+    fn possiveis_movimentos(&self, tabuleiro: &Tabuleiro, posicao: &Posicao) -> Vec<Posicao> {
+        let mut movimentos = Vec::new();
+        let direcao = self.direcao();
+
+        if let Some(um_passo) = posicao.deslocar(direcao, 0) {
+            if tabuleiro.peca_em(&um_passo).is_none() {
+                movimentos.push(um_passo);
+
+                if posicao.linha == self.linha_inicial() {
+                    if let Some(dois_passos) = posicao.deslocar(direcao * 2, 0) {
+                        if tabuleiro.peca_em(&dois_passos).is_none() {
+                            movimentos.push(dois_passos);
+                        }
+                    }
+                }
+            }
+        }
+
+        for delta_coluna in [-1, 1] {
+            if let Some(alvo) = posicao.deslocar(direcao, delta_coluna) {
+                let captura_em_passant = tabuleiro.en_passant == Some(alvo);
+                match tabuleiro.peca_em(&alvo) {
+                    Some(peca) if peca.dados().jogador != self.data.jogador => movimentos.push(alvo),
+                    None if captura_em_passant => movimentos.push(alvo),
+                    _ => {}
+                }
+            }
+        }
+
+        movimentos
+    }
+
+    // A pawn's diagonals count as attacked whether or not an enemy piece (or en passant target)
+    // actually sits there, unlike `possiveis_movimentos` — otherwise a king could legally castle
+    // through a square a pawn merely controls but hasn't captured on.
+    fn casas_atacadas(&self, _tabuleiro: &Tabuleiro, posicao: &Posicao) -> Vec<Posicao> {
+        let direcao = self.direcao();
+        [-1, 1]
+            .into_iter()
+            .filter_map(|delta_coluna| posicao.deslocar(direcao, delta_coluna))
+            .collect()
+    }
+
+    fn caixa_clone(&self) -> Box<dyn Peca> {
+        Box::new(Peao {
+            data: self.data.clone(),
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use crate::jogador::Jogador;
+use crate::peca::{Peca, PecaData, TipoPeca};
+use crate::posicao::Posicao;
+use crate::tabuleiro::Tabuleiro;
+
+pub struct Rainha {
+    pub data: PecaData,
+}
+
+impl Rainha {
+    pub fn new(jogador: Jogador) -> Self {
+        Rainha {
+            data: PecaData {
+                jogador,
+                nome: "Rainha".to_string(),
+            },
+        }
+    }
+}
+
+const DIRECOES: [(i32, i32); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+impl Peca for Rainha {
+    fn dados(&self) -> &PecaData {
+        &self.data
+    }
+
+    fn tipo(&self) -> TipoPeca {
+        TipoPeca::Rainha
+    }
+
+    fn possiveis_movimentos(&self, tabuleiro: &Tabuleiro, posicao: &Posicao) -> Vec<Posicao> {
+        DIRECOES
+            .iter()
+            .flat_map(|&direcao| tabuleiro.raio(posicao, direcao, self.data.jogador))
+            .collect()
+    }
+
+    fn caixa_clone(&self) -> Box<dyn Peca> {
+        Box::new(Rainha {
+            data: self.data.clone(),
+        })
+    }
+}
@@ -0,0 +1,47 @@
+use crate::peca::{Peca, PecaData, TipoPeca};
+use crate::posicao::Posicao;
+use crate::tabuleiro::Tabuleiro;
+
+pub struct Rei {
+    pub data: PecaData,
+}
+
+const OFFSETS: [(i32, i32); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+impl Peca for Rei {
+    fn dados(&self) -> &PecaData {
+        &self.data
+    }
+
+    fn tipo(&self) -> TipoPeca {
+        TipoPeca::Rei
+    }
+
+    fn possiveis_movimentos(&self, tabuleiro: &Tabuleiro, posicao: &Posicao) -> Vec<Posicao> {
+        // Castling is handled by `Tabuleiro::movimentos_legais`, since it also depends on the
+        // rook and on squares this piece alone can't see.
+        OFFSETS
+            .iter()
+            .filter_map(|&(delta_linha, delta_coluna)| posicao.deslocar(delta_linha, delta_coluna))
+            .filter(|destino| match tabuleiro.peca_em(destino) {
+                Some(peca) => peca.dados().jogador != self.data.jogador,
+                None => true,
+            })
+            .collect()
+    }
+
+    fn caixa_clone(&self) -> Box<dyn Peca> {
+        Box::new(Rei {
+            data: self.data.clone(),
+        })
+    }
+}
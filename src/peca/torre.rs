@@ -0,0 +1,44 @@
+use crate::jogador::Jogador;
+use crate::peca::{Peca, PecaData, TipoPeca};
+use crate::posicao::Posicao;
+use crate::tabuleiro::Tabuleiro;
+
+pub struct Torre {
+    pub data: PecaData,
+}
+
+impl Torre {
+    pub fn new(jogador: Jogador) -> Self {
+        Torre {
+            data: PecaData {
+                jogador,
+                nome: "Torre".to_string(),
+            },
+        }
+    }
+}
+
+const DIRECOES: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+impl Peca for Torre {
+    fn dados(&self) -> &PecaData {
+        &self.data
+    }
+
+    fn tipo(&self) -> TipoPeca {
+        TipoPeca::Torre
+    }
+
+    fn possiveis_movimentos(&self, tabuleiro: &Tabuleiro, posicao: &Posicao) -> Vec<Posicao> {
+        DIRECOES
+            .iter()
+            .flat_map(|&direcao| tabuleiro.raio(posicao, direcao, self.data.jogador))
+            .collect()
+    }
+
+    fn caixa_clone(&self) -> Box<dyn Peca> {
+        Box::new(Torre {
+            data: self.data.clone(),
+        })
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Posicao {
     pub linha: Linha,
     pub coluna: Coluna,
@@ -6,10 +6,22 @@ pub struct Posicao {
 
 impl Posicao {
     pub fn new(linha: Linha, coluna: Coluna) -> Self {
-        Posicao {
-            linha: linha,
-            coluna: coluna,
+        Posicao { linha, coluna }
+    }
+
+    // Index of this square within `Tabuleiro::casas`, laid out row-major by `Linha` then `Coluna`.
+    pub fn indice(&self) -> usize {
+        (self.linha as usize - 1) * 8 + (self.coluna as usize - 1)
+    }
+
+    // Offsets this square by (delta_linha, delta_coluna), returning `None` if it falls off the board.
+    pub fn deslocar(&self, delta_linha: i32, delta_coluna: i32) -> Option<Posicao> {
+        let linha = self.linha as i32 + delta_linha;
+        let coluna = self.coluna as i32 + delta_coluna;
+        if !(1..=8).contains(&linha) || !(1..=8).contains(&coluna) {
+            return None;
         }
+        Some(Posicao::new(Linha::from(linha), Coluna::from(coluna)))
     }
 }
 
@@ -68,3 +80,4 @@ impl From<i32> for Coluna {
         }
     }
 }
+
@@ -0,0 +1,629 @@
+use crate::casa::{Casa, OptionalPeca};
+use crate::jogador::Jogador;
+use crate::peca::{Peca, TipoPeca, criar_peca_promovida};
+use crate::posicao::{Coluna, Linha, Posicao};
+
+pub struct Tabuleiro {
+    pub casas: Vec<Casa>,
+    pub vez: Jogador,
+    // Target square a pawn may capture onto by en passant; valid for exactly one ply.
+    pub en_passant: Option<Posicao>,
+    pub rei_branco_moveu: bool,
+    pub rei_preto_moveu: bool,
+    pub torre_branca_a_moveu: bool,
+    pub torre_branca_h_moveu: bool,
+    pub torre_preta_a_moveu: bool,
+    pub torre_preta_h_moveu: bool,
+}
+
+impl Tabuleiro {
+    pub fn new() -> Self {
+        let mut casas: Vec<Casa> = Vec::new();
+        for i in 1..9 {
+            for j in 1..9 {
+                let casa = Casa {
+                    posicao: Posicao::new(Linha::from(i), Coluna::from(j)),
+                    peca: OptionalPeca::None,
+                };
+                casas.push(casa);
+            }
+        }
+
+        Tabuleiro {
+            casas,
+            vez: Jogador::Branco,
+            en_passant: None,
+            rei_branco_moveu: false,
+            rei_preto_moveu: false,
+            torre_branca_a_moveu: false,
+            torre_branca_h_moveu: false,
+            torre_preta_a_moveu: false,
+            torre_preta_h_moveu: false,
+        }
+    }
+
+    pub fn casa(&self, posicao: &Posicao) -> &Casa {
+        &self.casas[posicao.indice()]
+    }
+
+    pub fn casa_mut(&mut self, posicao: &Posicao) -> &mut Casa {
+        &mut self.casas[posicao.indice()]
+    }
+
+    pub fn peca_em(&self, posicao: &Posicao) -> Option<&dyn Peca> {
+        match &self.casa(posicao).peca {
+            OptionalPeca::Some(peca) => Some(peca.as_ref()),
+            OptionalPeca::None => None,
+        }
+    }
+
+    fn casas_ocupadas_por(&self, jogador: Jogador) -> Vec<Posicao> {
+        self.casas
+            .iter()
+            .filter(|casa| matches!(&casa.peca, OptionalPeca::Some(peca) if peca.dados().jogador == jogador))
+            .map(|casa| casa.posicao)
+            .collect()
+    }
+
+    fn posicao_do_rei(&self, jogador: Jogador) -> Option<Posicao> {
+        self.casas.iter().find_map(|casa| match &casa.peca {
+            OptionalPeca::Some(peca)
+                if peca.dados().jogador == jogador && peca.tipo() == TipoPeca::Rei =>
+            {
+                Some(casa.posicao)
+            }
+            _ => None,
+        })
+    }
+
+    // Every square a ray-casting piece reaches stepping in `direcao` from `origem`, stopping
+    // before a friendly piece and including an enemy piece as the final capture.
+    pub fn raio(&self, origem: &Posicao, direcao: (i32, i32), proprio: Jogador) -> Vec<Posicao> {
+        let mut alcance = Vec::new();
+        let mut atual = *origem;
+        while let Some(proxima) = atual.deslocar(direcao.0, direcao.1) {
+            match self.peca_em(&proxima) {
+                None => {
+                    alcance.push(proxima);
+                    atual = proxima;
+                }
+                Some(ocupante) if ocupante.dados().jogador != proprio => {
+                    alcance.push(proxima);
+                    break;
+                }
+                Some(_) => break,
+            }
+        }
+        alcance
+    }
+
+    // Pseudo-legal moves for the piece at `posicao`, ignoring whether they leave the mover's
+    // own king in check.
+    pub fn movimentos_pseudo_legais(&self, posicao: &Posicao) -> Vec<Posicao> {
+        match self.peca_em(posicao) {
+            Some(peca) => peca.possiveis_movimentos(self, posicao),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn posicao_atacada(&self, posicao: &Posicao, jogador: Jogador) -> bool {
+        self.casas_ocupadas_por(jogador).into_iter().any(|origem| {
+            match self.peca_em(&origem) {
+                Some(peca) => peca.casas_atacadas(self, &origem).contains(posicao),
+                None => false,
+            }
+        })
+    }
+
+    pub fn rei_em_xeque(&self, jogador: Jogador) -> bool {
+        match self.posicao_do_rei(jogador) {
+            Some(posicao_rei) => self.posicao_atacada(&posicao_rei, jogador.oponente()),
+            None => false,
+        }
+    }
+
+    // Castling moves available to the king at `posicao`: neither king nor rook has moved, the
+    // intermediate squares are empty, and the king is not in check on the start/transit/landing
+    // squares.
+    fn movimentos_de_roque(&self, posicao: &Posicao, jogador: Jogador) -> Vec<Posicao> {
+        let mut roques = Vec::new();
+        let (rei_moveu, torre_curta_moveu, torre_longa_moveu) = match jogador {
+            Jogador::Branco => (
+                self.rei_branco_moveu,
+                self.torre_branca_h_moveu,
+                self.torre_branca_a_moveu,
+            ),
+            Jogador::Preto => (
+                self.rei_preto_moveu,
+                self.torre_preta_h_moveu,
+                self.torre_preta_a_moveu,
+            ),
+        };
+
+        if rei_moveu || self.rei_em_xeque(jogador) {
+            return roques;
+        }
+
+        if !torre_curta_moveu && self.torre_em(&Posicao::new(posicao.linha, Coluna::Eight), jogador) {
+            let transito = [Coluna::Six, Coluna::Seven];
+            let livre = transito
+                .iter()
+                .all(|&c| self.casa(&Posicao::new(posicao.linha, c)).peca_vazia());
+            let seguro = transito
+                .iter()
+                .all(|&c| !self.posicao_atacada(&Posicao::new(posicao.linha, c), jogador.oponente()));
+            if livre && seguro {
+                roques.push(Posicao::new(posicao.linha, Coluna::Seven));
+            }
+        }
+
+        if !torre_longa_moveu && self.torre_em(&Posicao::new(posicao.linha, Coluna::One), jogador) {
+            let livre = [Coluna::Four, Coluna::Three, Coluna::Two]
+                .iter()
+                .all(|&c| self.casa(&Posicao::new(posicao.linha, c)).peca_vazia());
+            let seguro = [Coluna::Four, Coluna::Three]
+                .iter()
+                .all(|&c| !self.posicao_atacada(&Posicao::new(posicao.linha, c), jogador.oponente()));
+            if livre && seguro {
+                roques.push(Posicao::new(posicao.linha, Coluna::Three));
+            }
+        }
+
+        roques
+    }
+
+    // Whether a friendly rook still sits on `posicao` — a captured rook leaves the move flag
+    // untouched, so castling eligibility can't rely on that flag alone.
+    fn torre_em(&self, posicao: &Posicao, jogador: Jogador) -> bool {
+        matches!(
+            self.peca_em(posicao),
+            Some(peca) if peca.tipo() == TipoPeca::Torre && peca.dados().jogador == jogador
+        )
+    }
+
+    // All moves from `posicao` that are legal for its own side: pseudo-legal moves (plus
+    // castling, for a king) filtered to exclude anything that leaves the mover's king attacked.
+    pub fn movimentos_legais(&self, posicao: &Posicao) -> Vec<Posicao> {
+        let peca = match self.peca_em(posicao) {
+            Some(peca) => peca,
+            None => return Vec::new(),
+        };
+        let jogador = peca.dados().jogador;
+
+        let mut candidatos = self.movimentos_pseudo_legais(posicao);
+        if peca.tipo() == TipoPeca::Rei {
+            candidatos.extend(self.movimentos_de_roque(posicao, jogador));
+        }
+
+        candidatos
+            .into_iter()
+            .filter(|destino| {
+                let mut simulado = self.clone();
+                simulado.aplicar_movimento(posicao, destino);
+                !simulado.rei_em_xeque(jogador)
+            })
+            .collect()
+    }
+
+    // Mutates the board to reflect a move from `de` to `para`: relocates the piece, resolves en
+    // passant captures and castling rook hops, and updates the bookkeeping those rules depend
+    // on. Does not check legality itself; callers should consult `movimentos_legais` first.
+    // Promotes a pawn reaching the back rank to a queen; use `aplicar_movimento_com_promocao` to
+    // choose a different piece.
+    pub fn aplicar_movimento(&mut self, de: &Posicao, para: &Posicao) {
+        self.aplicar_movimento_com_promocao(de, para, TipoPeca::Rainha);
+    }
+
+    // Like `aplicar_movimento`, but promotes a pawn landing on the back rank to `promocao`
+    // instead of always defaulting to a queen. `promocao` is ignored for any other move.
+    pub fn aplicar_movimento_com_promocao(
+        &mut self,
+        de: &Posicao,
+        para: &Posicao,
+        promocao: TipoPeca,
+    ) {
+        let tipo = self.peca_em(de).map(|peca| peca.tipo());
+        let jogador = self.peca_em(de).map(|peca| peca.dados().jogador);
+
+        if tipo == Some(TipoPeca::Peao)
+            && Some(*para) == self.en_passant
+            && self.peca_em(para).is_none()
+        {
+            if let Some(jogador) = jogador {
+                let direcao_captura = match jogador {
+                    Jogador::Branco => -1,
+                    Jogador::Preto => 1,
+                };
+                if let Some(casa_capturada) = para.deslocar(direcao_captura, 0) {
+                    self.casa_mut(&casa_capturada).peca = OptionalPeca::None;
+                }
+            }
+        }
+
+        if tipo == Some(TipoPeca::Rei) {
+            let deslocamento = para.coluna as i32 - de.coluna as i32;
+            let torre_a_mover = if deslocamento == 2 {
+                Some((
+                    Posicao::new(de.linha, Coluna::Eight),
+                    Posicao::new(de.linha, Coluna::Six),
+                ))
+            } else if deslocamento == -2 {
+                Some((
+                    Posicao::new(de.linha, Coluna::One),
+                    Posicao::new(de.linha, Coluna::Four),
+                ))
+            } else {
+                None
+            };
+            if let Some((origem_torre, destino_torre)) = torre_a_mover {
+                let torre = self.casa_mut(&origem_torre).peca.tomar();
+                self.casa_mut(&destino_torre).peca = torre;
+            }
+        }
+
+        if let Some(jogador) = jogador {
+            self.marcar_movido(jogador, tipo, de);
+        }
+
+        self.en_passant = match tipo {
+            // The square the pawn passed over, not its landing square: that's what a capturing
+            // pawn targets and what `Peao::possiveis_movimentos` checks against.
+            Some(TipoPeca::Peao) if (de.linha as i32 - para.linha as i32).abs() == 2 => {
+                let direcao = if para.linha as i32 > de.linha as i32 { 1 } else { -1 };
+                de.deslocar(direcao, 0)
+            }
+            _ => None,
+        };
+
+        let movida = self.casa_mut(de).peca.tomar();
+        self.casa_mut(para).peca = movida;
+
+        if tipo == Some(TipoPeca::Peao) {
+            if let Some(jogador) = jogador {
+                let ultima_linha = match jogador {
+                    Jogador::Branco => Linha::H,
+                    Jogador::Preto => Linha::A,
+                };
+                if para.linha == ultima_linha {
+                    self.casa_mut(para).peca = OptionalPeca::Some(criar_peca_promovida(promocao, jogador));
+                }
+            }
+        }
+
+        self.vez = self.vez.oponente();
+    }
+
+    fn marcar_movido(&mut self, jogador: Jogador, tipo: Option<TipoPeca>, de: &Posicao) {
+        match (jogador, tipo) {
+            (Jogador::Branco, Some(TipoPeca::Rei)) => self.rei_branco_moveu = true,
+            (Jogador::Preto, Some(TipoPeca::Rei)) => self.rei_preto_moveu = true,
+            (Jogador::Branco, Some(TipoPeca::Torre)) if de.coluna == Coluna::One => {
+                self.torre_branca_a_moveu = true
+            }
+            (Jogador::Branco, Some(TipoPeca::Torre)) if de.coluna == Coluna::Eight => {
+                self.torre_branca_h_moveu = true
+            }
+            (Jogador::Preto, Some(TipoPeca::Torre)) if de.coluna == Coluna::One => {
+                self.torre_preta_a_moveu = true
+            }
+            (Jogador::Preto, Some(TipoPeca::Torre)) if de.coluna == Coluna::Eight => {
+                self.torre_preta_h_moveu = true
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Clone for Tabuleiro {
+    fn clone(&self) -> Self {
+        Tabuleiro {
+            casas: self.casas.clone(),
+            vez: self.vez,
+            en_passant: self.en_passant,
+            rei_branco_moveu: self.rei_branco_moveu,
+            rei_preto_moveu: self.rei_preto_moveu,
+            torre_branca_a_moveu: self.torre_branca_a_moveu,
+            torre_branca_h_moveu: self.torre_branca_h_moveu,
+            torre_preta_a_moveu: self.torre_preta_a_moveu,
+            torre_preta_h_moveu: self.torre_preta_h_moveu,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::peca::bispo::Bispo;
+    use crate::peca::cavalo::Cavalo;
+    use crate::peca::peao::Peao;
+    use crate::peca::rainha::Rainha;
+    use crate::peca::rei::Rei;
+    use crate::peca::torre::Torre;
+    use crate::peca::{PecaData, TipoPeca};
+
+    fn colocar(tabuleiro: &mut Tabuleiro, posicao: Posicao, peca: Box<dyn Peca>) {
+        tabuleiro.casa_mut(&posicao).peca = OptionalPeca::Some(peca);
+    }
+
+    fn pos(linha: i32, coluna: i32) -> Posicao {
+        Posicao::new(Linha::from(linha), Coluna::from(coluna))
+    }
+
+    fn peca_data(jogador: Jogador, nome: &str) -> PecaData {
+        PecaData {
+            jogador,
+            nome: nome.to_string(),
+        }
+    }
+
+    #[test]
+    fn peao_avanca_um_ou_dois_passos_da_linha_inicial() {
+        let mut tabuleiro = Tabuleiro::new();
+        let e2 = pos(2, 5);
+        colocar(&mut tabuleiro, e2, Box::new(Peao::new(Jogador::Branco)));
+
+        let movimentos = tabuleiro.movimentos_pseudo_legais(&e2);
+        assert_eq!(movimentos, vec![pos(3, 5), pos(4, 5)]);
+    }
+
+    #[test]
+    fn peao_nao_avanca_dois_passos_fora_da_linha_inicial() {
+        let mut tabuleiro = Tabuleiro::new();
+        let e3 = pos(3, 5);
+        colocar(&mut tabuleiro, e3, Box::new(Peao::new(Jogador::Branco)));
+
+        assert_eq!(tabuleiro.movimentos_pseudo_legais(&e3), vec![pos(4, 5)]);
+    }
+
+    #[test]
+    fn peao_captura_em_diagonal_apenas_se_houver_inimigo() {
+        let mut tabuleiro = Tabuleiro::new();
+        let e4 = pos(4, 5);
+        colocar(&mut tabuleiro, e4, Box::new(Peao::new(Jogador::Branco)));
+        colocar(
+            &mut tabuleiro,
+            pos(5, 6),
+            Box::new(Peao::new(Jogador::Preto)),
+        );
+
+        let movimentos = tabuleiro.movimentos_pseudo_legais(&e4);
+        assert!(movimentos.contains(&pos(5, 6)));
+        assert!(!movimentos.contains(&pos(5, 4)));
+    }
+
+    #[test]
+    fn peao_captura_em_passant_no_alvo_marcado() {
+        let mut tabuleiro = Tabuleiro::new();
+        let e5 = pos(5, 5);
+        colocar(&mut tabuleiro, e5, Box::new(Peao::new(Jogador::Branco)));
+        tabuleiro.en_passant = Some(pos(6, 6));
+
+        assert!(tabuleiro.movimentos_pseudo_legais(&e5).contains(&pos(6, 6)));
+    }
+
+    #[test]
+    fn cavalo_pula_sobre_pecas_mas_nao_captura_as_proprias() {
+        let mut tabuleiro = Tabuleiro::new();
+        let b1 = pos(1, 2);
+        colocar(
+            &mut tabuleiro,
+            b1,
+            Box::new(Cavalo {
+                data: peca_data(Jogador::Branco, "Cavalo"),
+            }),
+        );
+        colocar(&mut tabuleiro, pos(2, 4), Box::new(Peao::new(Jogador::Branco)));
+        colocar(&mut tabuleiro, pos(3, 1), Box::new(Peao::new(Jogador::Preto)));
+
+        let movimentos = tabuleiro.movimentos_pseudo_legais(&b1);
+        assert!(!movimentos.contains(&pos(2, 4)));
+        assert!(movimentos.contains(&pos(3, 1)));
+        assert!(movimentos.contains(&pos(3, 3)));
+    }
+
+    #[test]
+    fn torre_para_antes_de_peca_propria_e_captura_inimiga() {
+        let mut tabuleiro = Tabuleiro::new();
+        let a1 = pos(1, 1);
+        colocar(
+            &mut tabuleiro,
+            a1,
+            Box::new(Torre {
+                data: peca_data(Jogador::Branco, "Torre"),
+            }),
+        );
+        colocar(&mut tabuleiro, pos(1, 4), Box::new(Peao::new(Jogador::Branco)));
+        colocar(&mut tabuleiro, pos(4, 1), Box::new(Peao::new(Jogador::Preto)));
+
+        let movimentos = tabuleiro.movimentos_pseudo_legais(&a1);
+        assert!(movimentos.contains(&pos(1, 2)));
+        assert!(movimentos.contains(&pos(1, 3)));
+        assert!(!movimentos.contains(&pos(1, 4)));
+        assert!(movimentos.contains(&pos(2, 1)));
+        assert!(movimentos.contains(&pos(3, 1)));
+        assert!(movimentos.contains(&pos(4, 1)));
+        assert!(!movimentos.contains(&pos(5, 1)));
+    }
+
+    #[test]
+    fn rainha_combina_alcance_de_torre_e_bispo() {
+        let mut tabuleiro = Tabuleiro::new();
+        let d1 = pos(1, 4);
+        colocar(
+            &mut tabuleiro,
+            d1,
+            Box::new(Rainha {
+                data: peca_data(Jogador::Branco, "Rainha"),
+            }),
+        );
+        colocar(&mut tabuleiro, pos(4, 4), Box::new(Peao::new(Jogador::Preto)));
+
+        let movimentos = tabuleiro.movimentos_pseudo_legais(&d1);
+        assert!(movimentos.contains(&pos(1, 8))); // straight, along the first rank
+        assert!(movimentos.contains(&pos(4, 1))); // diagonal, toward a1
+        assert!(movimentos.contains(&pos(4, 4))); // straight, capturing the blocking pawn
+        assert!(!movimentos.contains(&pos(5, 4))); // can't see past the capture
+
+        assert_eq!(tabuleiro.peca_em(&d1).unwrap().tipo(), TipoPeca::Rainha);
+        assert_eq!(tabuleiro.peca_em(&d1).unwrap().dados().nome, "Rainha");
+        assert!(tabuleiro.peca_em(&d1).unwrap().mover(&tabuleiro, &d1, &pos(4, 4)));
+    }
+
+    #[test]
+    fn roque_curto_disponivel_quando_caminho_livre_e_seguro() {
+        let mut tabuleiro = Tabuleiro::new();
+        let e1 = pos(1, 5);
+        colocar(
+            &mut tabuleiro,
+            e1,
+            Box::new(Rei {
+                data: peca_data(Jogador::Branco, "Rei"),
+            }),
+        );
+        colocar(
+            &mut tabuleiro,
+            pos(1, 8),
+            Box::new(Torre {
+                data: peca_data(Jogador::Branco, "Torre"),
+            }),
+        );
+
+        assert!(tabuleiro.movimentos_legais(&e1).contains(&pos(1, 7)));
+    }
+
+    #[test]
+    fn roque_curto_indisponivel_sem_torre_presente() {
+        let mut tabuleiro = Tabuleiro::new();
+        let e1 = pos(1, 5);
+        colocar(
+            &mut tabuleiro,
+            e1,
+            Box::new(Rei {
+                data: peca_data(Jogador::Branco, "Rei"),
+            }),
+        );
+
+        assert!(!tabuleiro.movimentos_legais(&e1).contains(&pos(1, 7)));
+    }
+
+    #[test]
+    fn roque_curto_bloqueado_por_peao_inimigo_atacando_casa_de_transito() {
+        let mut tabuleiro = Tabuleiro::new();
+        let e1 = pos(1, 5);
+        colocar(
+            &mut tabuleiro,
+            e1,
+            Box::new(Rei {
+                data: peca_data(Jogador::Branco, "Rei"),
+            }),
+        );
+        colocar(
+            &mut tabuleiro,
+            pos(1, 8),
+            Box::new(Torre {
+                data: peca_data(Jogador::Branco, "Torre"),
+            }),
+        );
+        // A black pawn on g2 attacks f1 diagonally forward — an empty square it doesn't occupy,
+        // but that the king would still pass through on the way to g1.
+        colocar(&mut tabuleiro, pos(2, 7), Box::new(Peao::new(Jogador::Preto)));
+
+        assert!(tabuleiro.posicao_atacada(&pos(1, 6), Jogador::Preto));
+        assert!(!tabuleiro.movimentos_legais(&e1).contains(&pos(1, 7)));
+    }
+
+    #[test]
+    fn movimento_legal_filtra_lances_que_deixam_o_proprio_rei_em_xeque() {
+        let mut tabuleiro = Tabuleiro::new();
+        let e2 = pos(2, 5);
+        colocar(
+            &mut tabuleiro,
+            e2,
+            Box::new(Rei {
+                data: peca_data(Jogador::Branco, "Rei"),
+            }),
+        );
+        let d2 = pos(2, 4);
+        colocar(
+            &mut tabuleiro,
+            d2,
+            Box::new(Bispo {
+                data: peca_data(Jogador::Branco, "Bispo"),
+            }),
+        );
+        // A black rook pins the bishop to the king along the second rank.
+        colocar(
+            &mut tabuleiro,
+            pos(2, 1),
+            Box::new(Torre {
+                data: peca_data(Jogador::Preto, "Torre"),
+            }),
+        );
+
+        assert!(tabuleiro.movimentos_legais(&d2).is_empty());
+    }
+
+    #[test]
+    fn aplicar_movimento_de_roque_tambem_move_a_torre() {
+        let mut tabuleiro = Tabuleiro::new();
+        let e1 = pos(1, 5);
+        colocar(
+            &mut tabuleiro,
+            e1,
+            Box::new(Rei {
+                data: peca_data(Jogador::Branco, "Rei"),
+            }),
+        );
+        colocar(
+            &mut tabuleiro,
+            pos(1, 8),
+            Box::new(Torre {
+                data: peca_data(Jogador::Branco, "Torre"),
+            }),
+        );
+
+        tabuleiro.aplicar_movimento(&e1, &pos(1, 7));
+
+        assert!(tabuleiro.peca_em(&pos(1, 7)).is_some());
+        assert!(tabuleiro.peca_em(&pos(1, 6)).is_some());
+        assert!(tabuleiro.peca_em(&pos(1, 8)).is_none());
+    }
+
+    #[test]
+    fn aplicar_movimento_en_passant_remove_o_peao_capturado() {
+        let mut tabuleiro = Tabuleiro::new();
+        let d5 = pos(5, 4);
+        colocar(&mut tabuleiro, d5, Box::new(Peao::new(Jogador::Branco)));
+        colocar(&mut tabuleiro, pos(5, 5), Box::new(Peao::new(Jogador::Preto)));
+        tabuleiro.en_passant = Some(pos(6, 5));
+
+        tabuleiro.aplicar_movimento(&d5, &pos(6, 5));
+
+        assert!(tabuleiro.peca_em(&pos(5, 5)).is_none());
+        assert!(tabuleiro.peca_em(&pos(6, 5)).is_some());
+    }
+
+    #[test]
+    fn aplicar_movimento_promove_peao_a_rainha_por_padrao() {
+        let mut tabuleiro = Tabuleiro::new();
+        let g7 = pos(7, 4);
+        colocar(&mut tabuleiro, g7, Box::new(Peao::new(Jogador::Branco)));
+
+        tabuleiro.aplicar_movimento(&g7, &pos(8, 4));
+
+        let promovida = tabuleiro.peca_em(&pos(8, 4)).expect("peao promovido");
+        assert_eq!(promovida.tipo(), TipoPeca::Rainha);
+    }
+
+    #[test]
+    fn aplicar_movimento_com_promocao_escolhe_a_peca_pedida() {
+        let mut tabuleiro = Tabuleiro::new();
+        let b2 = pos(2, 4);
+        colocar(&mut tabuleiro, b2, Box::new(Peao::new(Jogador::Preto)));
+
+        tabuleiro.aplicar_movimento_com_promocao(&b2, &pos(1, 4), TipoPeca::Cavalo);
+
+        let promovida = tabuleiro.peca_em(&pos(1, 4)).expect("peao promovido");
+        assert_eq!(promovida.tipo(), TipoPeca::Cavalo);
+    }
+}